@@ -50,6 +50,49 @@ impl Chars {
     pub fn as_str(&self) -> &str {
         self
     }
+
+    /// Return a new `Chars` covering `range`, sharing the same underlying `Bytes` buffer (no
+    /// allocation or copy).
+    ///
+    /// Fails if either endpoint of `range` does not fall on a UTF-8 char boundary, or if
+    /// `range.start > range.end`.
+    pub fn slice(&self, range: std::ops::Range<usize>) -> Result<Chars, &'static str> {
+        if range.start > range.end {
+            return Err("Chars::slice: range start is greater than range end");
+        }
+        if !self.as_str().is_char_boundary(range.start) || !self.as_str().is_char_boundary(range.end)
+        {
+            return Err("Chars::slice: index not on a UTF-8 char boundary");
+        }
+        Ok(Chars(self.0.slice(range)))
+    }
+
+    /// Construct from `Bytes`, replacing invalid UTF-8 sequences with `U+FFFD`.
+    ///
+    /// If `bytes` is already valid UTF-8, the underlying buffer is shared; otherwise the
+    /// replacement forces a copy.
+    pub fn from_utf8_lossy(bytes: Bytes) -> Chars {
+        match Chars::from_bytes(bytes.clone()) {
+            Ok(chars) => chars,
+            Err(_) => Chars::from(String::from_utf8_lossy(&bytes).into_owned()),
+        }
+    }
+
+    /// Construct from a `&'static str` without copying.
+    pub const fn from_static(value: &'static str) -> Chars {
+        Chars(Bytes::from_static(value.as_bytes()))
+    }
+
+    /// Split into two `Chars` at byte offset `mid`, both sharing the same underlying `Bytes`
+    /// buffer (no allocation or copy).
+    ///
+    /// Fails if `mid` does not fall on a UTF-8 char boundary.
+    pub fn split_at(&self, mid: usize) -> Result<(Chars, Chars), &'static str> {
+        if !self.as_str().is_char_boundary(mid) {
+            return Err("Chars::split_at: index not on a UTF-8 char boundary");
+        }
+        Ok((Chars(self.0.slice(..mid)), Chars(self.0.slice(mid..))))
+    }
 }
 
 // Chars can be used as the key in a HashMap in a proto map. Because Chars is a wrapper around
@@ -127,6 +170,40 @@ impl fmt::Debug for Chars {
     }
 }
 
+/// Serializes as a plain string. `Chars` already guarantees valid UTF-8, so unlike a generic
+/// byte buffer this needs no extra validation pass.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Chars {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Chars {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CharsVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for CharsVisitor {
+            type Value = Chars;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Chars, E> {
+                Ok(Chars::from(v.to_owned()))
+            }
+
+            fn visit_string<E: serde::de::Error>(self, v: String) -> Result<Chars, E> {
+                Ok(Chars::from(v))
+            }
+        }
+
+        deserializer.deserialize_string(CharsVisitor)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::collections::HashMap;
@@ -150,4 +227,50 @@ mod test {
         assert_eq!(format!("{}", string), format!("{}", chars));
         assert_eq!(format!("{:?}", string), format!("{:?}", chars));
     }
+
+    #[test]
+    fn test_slice() {
+        let chars: Chars = "hello world".into();
+
+        assert_eq!("hello", chars.slice(0..5).unwrap().as_str());
+        assert_eq!("world", chars.slice(6..11).unwrap().as_str());
+
+        // "é" is 2 bytes (U+00E9), so byte offset 1 falls in the middle of it.
+        let chars: Chars = "é".into();
+        chars.slice(0..1).unwrap_err();
+    }
+
+    #[test]
+    fn test_from_utf8_lossy() {
+        assert_eq!("hello", Chars::from_utf8_lossy("hello".into()).as_str());
+        assert_eq!(
+            "\u{FFFD}",
+            Chars::from_utf8_lossy(vec![0xFF].into()).as_str()
+        );
+    }
+
+    #[test]
+    fn test_from_static() {
+        assert_eq!("hello", Chars::from_static("hello").as_str());
+    }
+
+    #[test]
+    fn test_split_at() {
+        let chars: Chars = "hello world".into();
+        let (left, right) = chars.split_at(5).unwrap();
+        assert_eq!("hello", left.as_str());
+        assert_eq!(" world", right.as_str());
+
+        let chars: Chars = "é".into();
+        chars.split_at(1).unwrap_err();
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let chars: Chars = "hello world".into();
+        let json = serde_json::to_string(&chars).unwrap();
+        assert_eq!("\"hello world\"", json);
+        assert_eq!(chars, serde_json::from_str::<Chars>(&json).unwrap());
+    }
 }
@@ -1,3 +1,5 @@
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
 use crate::well_known_types::duration::Duration;
 use crate::SpecialFields;
 
@@ -8,6 +10,195 @@ impl Duration {
         nanos: 0,
         special_fields: SpecialFields::new(),
     };
+
+    /// Bring `seconds`/`nanos` into the canonical protobuf `Duration` form: `nanos` is in
+    /// `(-1_000_000_000, 1_000_000_000)` and has the same sign as `seconds` (or is zero).
+    ///
+    /// Overflowing seconds saturate to `i64::MAX`/`i64::MIN` rather than wrapping.
+    pub fn normalize(&mut self) {
+        if self.nanos <= -1_000_000_000 || self.nanos >= 1_000_000_000 {
+            match self.seconds.checked_add((self.nanos / 1_000_000_000) as i64) {
+                Some(seconds) => {
+                    self.seconds = seconds;
+                    self.nanos %= 1_000_000_000;
+                }
+                None => {
+                    if self.nanos > 0 {
+                        self.seconds = i64::MAX;
+                        self.nanos = 999_999_999;
+                    } else {
+                        self.seconds = i64::MIN;
+                        self.nanos = -999_999_999;
+                    }
+                    return;
+                }
+            }
+        }
+
+        if self.seconds > 0 && self.nanos < 0 {
+            self.seconds -= 1;
+            self.nanos += 1_000_000_000;
+        } else if self.seconds < 0 && self.nanos > 0 {
+            self.seconds += 1;
+            self.nanos -= 1_000_000_000;
+        }
+    }
+
+    /// Add two durations, returning `None` if the result's seconds don't fit in an `i64`.
+    ///
+    /// The nanos-carry from `normalize()` is folded into the seconds addition before it's
+    /// checked, so overflow caused by the carry (not just by `self.seconds + other.seconds`
+    /// directly) is also caught.
+    pub fn checked_add(&self, other: &Duration) -> Option<Duration> {
+        let nanos = self.nanos as i64 + other.nanos as i64;
+        let seconds = self
+            .seconds
+            .checked_add(other.seconds)?
+            .checked_add(nanos / 1_000_000_000)?;
+        let mut result = Duration {
+            seconds,
+            nanos: (nanos % 1_000_000_000) as i32,
+            ..Default::default()
+        };
+        result.normalize();
+        Some(result)
+    }
+
+    /// Subtract two durations, returning `None` if the result's seconds don't fit in an `i64`.
+    ///
+    /// See [`Self::checked_add`] for why the nanos-carry is folded in before the overflow check.
+    pub fn checked_sub(&self, other: &Duration) -> Option<Duration> {
+        let nanos = self.nanos as i64 - other.nanos as i64;
+        let seconds = self
+            .seconds
+            .checked_sub(other.seconds)?
+            .checked_add(nanos / 1_000_000_000)?;
+        let mut result = Duration {
+            seconds,
+            nanos: (nanos % 1_000_000_000) as i32,
+            ..Default::default()
+        };
+        result.normalize();
+        Some(result)
+    }
+}
+
+/// Adds two durations, saturating on `i64` seconds overflow.
+impl Add for Duration {
+    type Output = Duration;
+
+    fn add(self, other: Duration) -> Duration {
+        self.checked_add(&other).unwrap_or_else(|| {
+            // Don't use `self.seconds`'s sign to pick the saturation bound: it's only a valid
+            // proxy for the sum's sign if `self`/`other` are already in canonical form (`nanos`
+            // matching `seconds`'s sign). Widen to `i128`, which can't itself overflow for two
+            // `i64`s, and read the direction off the actual sum instead.
+            if self.seconds as i128 + other.seconds as i128 >= 0 {
+                Duration {
+                    seconds: i64::MAX,
+                    nanos: 999_999_999,
+                    ..Default::default()
+                }
+            } else {
+                Duration {
+                    seconds: i64::MIN,
+                    nanos: -999_999_999,
+                    ..Default::default()
+                }
+            }
+        })
+    }
+}
+
+/// Subtracts two durations, saturating on `i64` seconds overflow.
+impl Sub for Duration {
+    type Output = Duration;
+
+    fn sub(self, other: Duration) -> Duration {
+        self.checked_sub(&other).unwrap_or_else(|| {
+            if self.seconds as i128 - other.seconds as i128 >= 0 {
+                Duration {
+                    seconds: i64::MAX,
+                    nanos: 999_999_999,
+                    ..Default::default()
+                }
+            } else {
+                Duration {
+                    seconds: i64::MIN,
+                    nanos: -999_999_999,
+                    ..Default::default()
+                }
+            }
+        })
+    }
+}
+
+impl Neg for Duration {
+    type Output = Duration;
+
+    fn neg(self) -> Duration {
+        let mut result = Duration {
+            seconds: self.seconds.checked_neg().unwrap_or(i64::MAX),
+            nanos: -self.nanos,
+            ..Default::default()
+        };
+        result.normalize();
+        result
+    }
+}
+
+impl AddAssign for Duration {
+    fn add_assign(&mut self, other: Duration) {
+        *self = self.clone() + other;
+    }
+}
+
+impl SubAssign for Duration {
+    fn sub_assign(&mut self, other: Duration) {
+        *self = self.clone() - other;
+    }
+}
+
+/// Multiplies a duration by a scalar, saturating on `i64` seconds overflow.
+impl Mul<i32> for Duration {
+    type Output = Duration;
+
+    fn mul(self, rhs: i32) -> Duration {
+        let total_nanos = self.seconds as i128 * 1_000_000_000 + self.nanos as i128;
+        let multiplied = total_nanos.saturating_mul(rhs as i128);
+        let multiplied = multiplied.clamp(
+            i64::MIN as i128 * 1_000_000_000 - 999_999_999,
+            i64::MAX as i128 * 1_000_000_000 + 999_999_999,
+        );
+        let mut result = Duration {
+            seconds: (multiplied / 1_000_000_000) as i64,
+            nanos: (multiplied % 1_000_000_000) as i32,
+            ..Default::default()
+        };
+        result.normalize();
+        result
+    }
+}
+
+/// Divides a duration by a scalar.
+///
+/// # Panics
+///
+/// If `rhs` is zero.
+impl Div<i32> for Duration {
+    type Output = Duration;
+
+    fn div(self, rhs: i32) -> Duration {
+        let total_nanos = self.seconds as i128 * 1_000_000_000 + self.nanos as i128;
+        let divided = total_nanos / rhs as i128;
+        let mut result = Duration {
+            seconds: (divided / 1_000_000_000) as i64,
+            nanos: (divided % 1_000_000_000) as i32,
+            ..Default::default()
+        };
+        result.normalize();
+        result
+    }
 }
 
 /// Convert from `std::time::Duration`.
@@ -17,11 +208,13 @@ impl Duration {
 /// If `std::time::Duration` value is outside of `Duration` supported range.
 impl From<std::time::Duration> for Duration {
     fn from(duration: std::time::Duration) -> Self {
-        Duration {
+        let mut result = Duration {
             seconds: duration.as_secs() as i64,
             nanos: duration.subsec_nanos() as i32,
             ..Default::default()
-        }
+        };
+        result.normalize();
+        result
     }
 }
 
@@ -74,4 +267,149 @@ mod test {
         })
         .unwrap_err();
     }
+
+    #[test]
+    fn normalize() {
+        fn check(mut duration: Duration, expected: Duration) {
+            duration.normalize();
+            assert_eq!(expected, duration);
+        }
+
+        check(
+            Duration {
+                seconds: 1,
+                nanos: 1_500_000_000,
+                ..Default::default()
+            },
+            Duration {
+                seconds: 2,
+                nanos: 500_000_000,
+                ..Default::default()
+            },
+        );
+
+        check(
+            Duration {
+                seconds: 1,
+                nanos: -500_000_000,
+                ..Default::default()
+            },
+            Duration {
+                seconds: 0,
+                nanos: 500_000_000,
+                ..Default::default()
+            },
+        );
+
+        check(
+            Duration {
+                seconds: -1,
+                nanos: 500_000_000,
+                ..Default::default()
+            },
+            Duration {
+                seconds: 0,
+                nanos: -500_000_000,
+                ..Default::default()
+            },
+        );
+
+        check(
+            Duration {
+                seconds: i64::MAX,
+                nanos: 1_000_000_000,
+                ..Default::default()
+            },
+            Duration {
+                seconds: i64::MAX,
+                nanos: 999_999_999,
+                ..Default::default()
+            },
+        );
+
+        check(
+            Duration {
+                seconds: i64::MIN,
+                nanos: -1_000_000_000,
+                ..Default::default()
+            },
+            Duration {
+                seconds: i64::MIN,
+                nanos: -999_999_999,
+                ..Default::default()
+            },
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let one_half = Duration {
+            seconds: 0,
+            nanos: 500_000_000,
+            ..Default::default()
+        };
+        let one = Duration {
+            seconds: 1,
+            nanos: 0,
+            ..Default::default()
+        };
+
+        assert_eq!(one, one_half.clone() + one_half.clone());
+        assert_eq!(one_half, one.clone() - one_half.clone());
+        assert_eq!(
+            Duration {
+                seconds: -1,
+                nanos: 0,
+                ..Default::default()
+            },
+            -one.clone()
+        );
+
+        let mut acc = Duration::ZERO;
+        acc += one_half.clone();
+        acc += one_half.clone();
+        assert_eq!(one, acc);
+        acc -= one_half.clone();
+        assert_eq!(one_half, acc);
+
+        assert_eq!(one, one_half.clone() * 2);
+        assert_eq!(one_half, one.clone() / 2);
+
+        let max = Duration {
+            seconds: i64::MAX,
+            nanos: 0,
+            ..Default::default()
+        };
+        let min = Duration {
+            seconds: i64::MIN,
+            nanos: 0,
+            ..Default::default()
+        };
+        assert_eq!(None, max.checked_add(&one));
+        assert_eq!(None, min.checked_sub(&one));
+    }
+
+    #[test]
+    fn checked_arithmetic_overflow_from_nanos_carry() {
+        // `seconds` alone doesn't overflow, but the nanos-carry from normalization pushes it
+        // past `i64::MAX`/`i64::MIN`.
+        let max_minus_epsilon = Duration {
+            seconds: i64::MAX,
+            nanos: 999_999_999,
+            ..Default::default()
+        };
+        let epsilon = Duration {
+            seconds: 0,
+            nanos: 999_999_999,
+            ..Default::default()
+        };
+        assert_eq!(None, max_minus_epsilon.checked_add(&epsilon));
+
+        let min_plus_epsilon = Duration {
+            seconds: i64::MIN,
+            nanos: -999_999_999,
+            ..Default::default()
+        };
+        assert_eq!(None, min_plus_epsilon.checked_sub(&epsilon));
+    }
 }
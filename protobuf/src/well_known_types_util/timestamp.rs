@@ -0,0 +1,319 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::well_known_types::timestamp::Timestamp;
+use crate::SpecialFields;
+
+impl Timestamp {
+    /// `1970-01-01T00:00:00Z`.
+    pub const UNIX_EPOCH: Timestamp = Timestamp {
+        seconds: 0,
+        nanos: 0,
+        special_fields: SpecialFields::new(),
+    };
+}
+
+/// Convert from `std::time::SystemTime`.
+impl From<SystemTime> for Timestamp {
+    fn from(time: SystemTime) -> Self {
+        match time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => Timestamp {
+                seconds: duration.as_secs() as i64,
+                nanos: duration.subsec_nanos() as i32,
+                ..Default::default()
+            },
+            Err(err) => {
+                // `time` is before the Unix epoch: the duration counts forward from `time` to
+                // the epoch, so negate it and then renormalize so `nanos` stays non-negative.
+                let before_epoch = err.duration();
+                let mut seconds = -(before_epoch.as_secs() as i64);
+                let mut nanos = -(before_epoch.subsec_nanos() as i32);
+                if nanos < 0 {
+                    seconds -= 1;
+                    nanos += 1_000_000_000;
+                }
+                Timestamp {
+                    seconds,
+                    nanos,
+                    ..Default::default()
+                }
+            }
+        }
+    }
+}
+
+/// Convert to `std::time::SystemTime`.
+impl TryInto<SystemTime> for Timestamp {
+    type Error = &'static str;
+
+    fn try_into(self) -> std::result::Result<SystemTime, Self::Error> {
+        if !(0..1_000_000_000).contains(&self.nanos) {
+            return Err("proto timestamp nanos out of range");
+        }
+
+        if self.seconds >= 0 {
+            UNIX_EPOCH.checked_add(Duration::new(self.seconds as u64, self.nanos as u32))
+        } else {
+            let seconds_before_epoch = self
+                .seconds
+                .checked_neg()
+                .ok_or("proto timestamp out of range")? as u64;
+            UNIX_EPOCH
+                .checked_sub(Duration::from_secs(seconds_before_epoch))
+                .and_then(|time| time.checked_add(Duration::from_nanos(self.nanos as u64)))
+        }
+        .ok_or("proto timestamp out of range")
+    }
+}
+
+/// An error parsing a [`Timestamp`] from an RFC 3339 string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimestampParseError {
+    /// The input did not look like `YYYY-MM-DDTHH:MM:SS[.frac](Z|±HH:MM)`.
+    InvalidFormat,
+    /// A field was syntactically valid but out of range (e.g. month 13, minute 99).
+    OutOfRange,
+}
+
+impl fmt::Display for TimestampParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TimestampParseError::InvalidFormat => write!(f, "invalid RFC 3339 timestamp"),
+            TimestampParseError::OutOfRange => {
+                write!(f, "RFC 3339 timestamp field out of range")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TimestampParseError {}
+
+// Days since `1970-01-01` for the given proleptic Gregorian civil date, using Howard Hinnant's
+// `days_from_civil` algorithm (the inverse of the computation used by `Display`, below).
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let mp = if month > 2 { month as i64 - 3 } else { month as i64 + 9 };
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+// Inverse of `days_from_civil`: the proleptic Gregorian civil date for the given number of days
+// since `1970-01-01`.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    (if m <= 2 { y + 1 } else { y }, m, d)
+}
+
+/// Format as RFC 3339, e.g. `1985-04-12T23:20:50.520Z`.
+///
+/// `nanos` is rendered as a trailing fractional-second field trimmed to 0, 3, 6, or 9 digits,
+/// whichever is shortest without losing precision. The timestamp is always UTC (`Z`).
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let days = self.seconds.div_euclid(86_400);
+        let seconds_of_day = self.seconds.rem_euclid(86_400);
+        let (year, month, day) = civil_from_days(days);
+
+        let hour = seconds_of_day / 3600;
+        let minute = (seconds_of_day % 3600) / 60;
+        let second = seconds_of_day % 60;
+
+        write!(
+            f,
+            "{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}"
+        )?;
+
+        if self.nanos != 0 {
+            if self.nanos % 1_000_000 == 0 {
+                write!(f, ".{:03}", self.nanos / 1_000_000)?;
+            } else if self.nanos % 1_000 == 0 {
+                write!(f, ".{:06}", self.nanos / 1_000)?;
+            } else {
+                write!(f, ".{:09}", self.nanos)?;
+            }
+        }
+
+        write!(f, "Z")
+    }
+}
+
+/// Parse from RFC 3339, e.g. `1985-04-12T23:20:50.52Z` or `1985-04-12T23:20:50.52-07:00`.
+impl FromStr for Timestamp {
+    type Err = TimestampParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+        // "YYYY-MM-DDTHH:MM:SS" is always exactly 19 bytes.
+        if bytes.len() < 20 || bytes[4] != b'-' || bytes[7] != b'-' || bytes[13] != b':' || bytes[16] != b':' {
+            return Err(TimestampParseError::InvalidFormat);
+        }
+        if bytes[10] != b'T' && bytes[10] != b't' {
+            return Err(TimestampParseError::InvalidFormat);
+        }
+        // The ASCII separator checks above only guarantee char boundaries at the slice offsets
+        // up to byte 17; a multi-byte character starting at byte 17 or 18 would make `s[17..19]`/`&s[19..]` below panic
+        // instead of erroring, since 19 wouldn't land on a char boundary.
+        if !s.is_char_boundary(19) {
+            return Err(TimestampParseError::InvalidFormat);
+        }
+
+        let year: i64 = s[0..4].parse().map_err(|_| TimestampParseError::InvalidFormat)?;
+        let month: u32 = s[5..7].parse().map_err(|_| TimestampParseError::InvalidFormat)?;
+        let day: u32 = s[8..10].parse().map_err(|_| TimestampParseError::InvalidFormat)?;
+        let hour: i64 = s[11..13].parse().map_err(|_| TimestampParseError::InvalidFormat)?;
+        let minute: i64 = s[14..16].parse().map_err(|_| TimestampParseError::InvalidFormat)?;
+        let second: i64 = s[17..19].parse().map_err(|_| TimestampParseError::InvalidFormat)?;
+
+        if !(1..=12).contains(&month) || day == 0 || day > 31 || !(0..24).contains(&hour)
+            || !(0..60).contains(&minute) || !(0..60).contains(&second)
+        {
+            return Err(TimestampParseError::OutOfRange);
+        }
+
+        let mut rest = &s[19..];
+
+        let mut nanos: i32 = 0;
+        if let Some(frac) = rest.strip_prefix('.') {
+            let digits_end = frac
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(frac.len());
+            if digits_end == 0 {
+                return Err(TimestampParseError::InvalidFormat);
+            }
+            let (digits, remainder) = frac.split_at(digits_end);
+            let mut padded = digits.to_string();
+            padded.truncate(9);
+            while padded.len() < 9 {
+                padded.push('0');
+            }
+            nanos = padded.parse().map_err(|_| TimestampParseError::InvalidFormat)?;
+            rest = remainder;
+        }
+
+        let offset_seconds: i64 = if rest == "Z" || rest == "z" {
+            0
+        } else if rest.len() == 6 && (rest.starts_with('+') || rest.starts_with('-')) && rest.as_bytes()[3] == b':' {
+            let sign: i64 = if rest.starts_with('-') { -1 } else { 1 };
+            let offset_hours: i64 = rest[1..3].parse().map_err(|_| TimestampParseError::InvalidFormat)?;
+            let offset_minutes: i64 = rest[4..6].parse().map_err(|_| TimestampParseError::InvalidFormat)?;
+            if offset_hours > 23 || offset_minutes > 59 {
+                return Err(TimestampParseError::OutOfRange);
+            }
+            sign * (offset_hours * 3600 + offset_minutes * 60)
+        } else {
+            return Err(TimestampParseError::InvalidFormat);
+        };
+
+        let days = days_from_civil(year, month, day);
+        let seconds = days * 86_400 + hour * 3600 + minute * 60 + second - offset_seconds;
+
+        Ok(Timestamp {
+            seconds,
+            nanos,
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::well_known_types::timestamp::Timestamp;
+
+    #[test]
+    fn to_from_system_time() {
+        assert_eq!(Timestamp::UNIX_EPOCH, Timestamp::from(UNIX_EPOCH));
+        assert_eq!(
+            UNIX_EPOCH,
+            TryInto::<std::time::SystemTime>::try_into(Timestamp::UNIX_EPOCH).unwrap()
+        );
+
+        let time = UNIX_EPOCH + Duration::new(4_123, 456_000_000);
+        assert_eq!(time, TryInto::<std::time::SystemTime>::try_into(Timestamp::from(time)).unwrap());
+
+        let before_epoch = UNIX_EPOCH - Duration::new(10, 0) + Duration::new(0, 250_000_000);
+        assert_eq!(
+            before_epoch,
+            TryInto::<std::time::SystemTime>::try_into(Timestamp::from(before_epoch)).unwrap()
+        );
+    }
+
+    #[test]
+    fn display() {
+        fn check(timestamp: Timestamp, expected: &str) {
+            assert_eq!(expected, timestamp.to_string());
+        }
+
+        check(Timestamp::UNIX_EPOCH, "1970-01-01T00:00:00Z");
+        check(
+            Timestamp {
+                seconds: 482_196_050,
+                nanos: 520_000_000,
+                ..Default::default()
+            },
+            "1985-04-12T23:20:50.520Z",
+        );
+        check(
+            Timestamp {
+                seconds: -1,
+                nanos: 0,
+                ..Default::default()
+            },
+            "1969-12-31T23:59:59Z",
+        );
+    }
+
+    #[test]
+    fn parse() {
+        fn check(s: &str, expected: Timestamp) {
+            assert_eq!(expected, s.parse::<Timestamp>().unwrap());
+        }
+
+        check(
+            "1985-04-12T23:20:50.52Z",
+            Timestamp {
+                seconds: 482_196_050,
+                nanos: 520_000_000,
+                ..Default::default()
+            },
+        );
+        check(
+            "1970-01-01T00:00:00Z",
+            Timestamp::UNIX_EPOCH,
+        );
+        check(
+            "1985-04-12T16:20:50.52-07:00",
+            Timestamp {
+                seconds: 482_196_050,
+                nanos: 520_000_000,
+                ..Default::default()
+            },
+        );
+
+        "not a timestamp".parse::<Timestamp>().unwrap_err();
+        "1985-13-12T23:20:50Z".parse::<Timestamp>().unwrap_err();
+    }
+
+    #[test]
+    fn round_trip() {
+        let timestamp = Timestamp {
+            seconds: 482_196_050,
+            nanos: 520_000_000,
+            ..Default::default()
+        };
+        assert_eq!(timestamp, timestamp.to_string().parse().unwrap());
+    }
+}